@@ -0,0 +1,17 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum ContributorError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    Unauthorized = 3,
+    InvalidGitHubHandle = 4,
+    GitHubHandleTaken = 5,
+    ContributorAlreadyExists = 6,
+    ContributorNotFound = 7,
+    ReputationOverflow = 8,
+    ContractPaused = 9,
+    RoleNotHeld = 10,
+}
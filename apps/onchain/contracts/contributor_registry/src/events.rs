@@ -0,0 +1,114 @@
+use soroban_sdk::{symbol_short, Address, BytesN, Env};
+
+use crate::storage::Role;
+
+/// Emitted when the admin role moves to a new address.
+pub struct AdminChangedEvent {
+    pub old_admin: Address,
+    pub new_admin: Address,
+}
+
+impl AdminChangedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("changed")),
+            (self.old_admin.clone(), self.new_admin.clone()),
+        );
+    }
+}
+
+/// Emitted when an admin transfer is proposed but not yet accepted.
+pub struct AdminTransferProposedEvent {
+    pub current_admin: Address,
+    pub proposed_admin: Address,
+}
+
+impl AdminTransferProposedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("admin"), symbol_short!("proposed")),
+            (self.current_admin.clone(), self.proposed_admin.clone()),
+        );
+    }
+}
+
+/// Emitted when an account is granted a [`Role`].
+pub struct RoleGrantedEvent {
+    pub account: Address,
+    pub role: Role,
+}
+
+impl RoleGrantedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("role"), symbol_short!("granted")),
+            (self.account.clone(), self.role),
+        );
+    }
+}
+
+/// Emitted when an account's [`Role`] is revoked.
+pub struct RoleRevokedEvent {
+    pub account: Address,
+    pub role: Role,
+}
+
+impl RoleRevokedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("role"), symbol_short!("revoked")),
+            (self.account.clone(), self.role),
+        );
+    }
+}
+
+/// Emitted when the contract is paused.
+pub struct PausedEvent {
+    pub admin: Address,
+}
+
+impl PausedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events()
+            .publish((symbol_short!("paused"),), self.admin.clone());
+    }
+}
+
+/// Emitted when the contract is unpaused.
+pub struct UnpausedEvent {
+    pub admin: Address,
+}
+
+impl UnpausedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events()
+            .publish((symbol_short!("unpaused"),), self.admin.clone());
+    }
+}
+
+/// Emitted when a contributor is removed from the registry.
+pub struct ContributorRemovedEvent {
+    pub address: Address,
+}
+
+impl ContributorRemovedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events()
+            .publish((symbol_short!("contrib"), symbol_short!("removed")), self.address.clone());
+    }
+}
+
+/// Emitted when the contract WASM is upgraded.
+pub struct UpgradedEvent {
+    pub admin: Address,
+    pub new_wasm_hash: BytesN<32>,
+}
+
+impl UpgradedEvent {
+    pub fn publish(&self, env: &Env) {
+        env.events().publish(
+            (symbol_short!("contract"), symbol_short!("upgraded")),
+            (self.admin.clone(), self.new_wasm_hash.clone()),
+        );
+    }
+}
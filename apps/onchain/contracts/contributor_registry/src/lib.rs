@@ -5,9 +5,15 @@ mod events;
 mod storage;
 
 use errors::ContributorError;
-use events::{AdminChangedEvent, UpgradedEvent};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String};
-use storage::{ContributorData, DataKey};
+use events::{
+    AdminChangedEvent, AdminTransferProposedEvent, ContributorRemovedEvent, PausedEvent,
+    RoleGrantedEvent, RoleRevokedEvent, UnpausedEvent, UpgradedEvent,
+};
+use soroban_sdk::{contract, contractimpl, Address, BytesN, Env, String, Vec};
+use storage::{ContributorData, DataKey, ReputationCheckpoint, Role};
+
+/// Maximum number of contributors returned by a single [`ContributorRegistryContract::list_contributors`] call.
+const MAX_PAGE_SIZE: u32 = 100;
 
 #[contract]
 pub struct ContributorRegistryContract;
@@ -31,6 +37,23 @@ impl ContributorRegistryContract {
         Ok(())
     }
 
+    fn is_admin(env: &Env, account: &Address) -> Result<bool, ContributorError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContributorError::NotInitialized)?;
+        Ok(*account == stored_admin)
+    }
+
+    fn ensure_not_paused(env: &Env) -> Result<(), ContributorError> {
+        let paused: bool = env.storage().instance().get(&DataKey::Paused).unwrap_or(false);
+        if paused {
+            return Err(ContributorError::ContractPaused);
+        }
+        Ok(())
+    }
+
     /// Initialize the contract with an admin address
     pub fn initialize(env: Env, admin: Address) -> Result<(), ContributorError> {
         if env.storage().instance().has(&DataKey::Admin) {
@@ -50,6 +73,7 @@ impl ContributorRegistryContract {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(ContributorError::NotInitialized);
         }
+        Self::ensure_not_paused(&env)?;
         address.require_auth();
         if github_handle.is_empty() {
             return Err(ContributorError::InvalidGitHubHandle);
@@ -76,6 +100,22 @@ impl ContributorRegistryContract {
             .persistent()
             .set(&DataKey::GitHubIndex(github_handle), &address);
 
+        let index_len: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ContributorIndexLen)
+            .unwrap_or(0);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ContributorIndex(index_len), &address);
+        env.storage()
+            .instance()
+            .set(&DataKey::ContributorIndexLen, &(index_len + 1));
+        let count = Self::get_contributor_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::ContributorCount, &(count + 1));
+
         Ok(())
     }
 
@@ -88,6 +128,7 @@ impl ContributorRegistryContract {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(ContributorError::NotInitialized);
         }
+        Self::ensure_not_paused(&env)?;
         address.require_auth();
         if github_handle.is_empty() {
             return Err(ContributorError::InvalidGitHubHandle);
@@ -116,22 +157,85 @@ impl ContributorRegistryContract {
         Ok(())
     }
 
-    /// Update the reputation score of a contributor (admin only)
+    /// Remove a contributor's registration.
+    ///
+    /// Callable by the contributor themselves or by the admin. Removes both
+    /// the `Contributor` entry and its paired `GitHubIndex` entry, and
+    /// decrements the live contributor count. Emits [`ContributorRemovedEvent`].
+    pub fn remove_contributor(
+        env: Env,
+        caller: Address,
+        address: Address,
+    ) -> Result<(), ContributorError> {
+        Self::ensure_not_paused(&env)?;
+        if caller != address && !Self::is_admin(&env, &caller)? {
+            return Err(ContributorError::Unauthorized);
+        }
+        caller.require_auth();
+        let contributor: ContributorData = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Contributor(address.clone()))
+            .ok_or(ContributorError::ContributorNotFound)?;
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Contributor(address.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GitHubIndex(contributor.github_handle));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ReputationHistory(address.clone()));
+        Self::clear_contributor_index(&env, &address);
+
+        let count = Self::get_contributor_count(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::ContributorCount, &count.saturating_sub(1));
+
+        ContributorRemovedEvent { address }.publish(&env);
+        Ok(())
+    }
+
+    /// Remove `address`'s slot from the append-only contributor index,
+    /// leaving a hole, so a later re-registration cannot produce a
+    /// duplicate entry in [`Self::list_contributors`].
+    fn clear_contributor_index(env: &Env, address: &Address) {
+        let index_len: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ContributorIndexLen)
+            .unwrap_or(0);
+        for i in 0..index_len {
+            let indexed: Option<Address> =
+                env.storage().persistent().get(&DataKey::ContributorIndex(i));
+            if indexed.as_ref() == Some(address) {
+                env.storage().persistent().remove(&DataKey::ContributorIndex(i));
+                break;
+            }
+        }
+    }
+
+    /// Update the reputation score of a contributor.
+    ///
+    /// Callable by the stored admin or by any account holding [`Role::Moderator`]
+    /// or [`Role::Admin`], so score curation can be delegated without sharing
+    /// the master admin key.
     pub fn update_reputation(
         env: Env,
-        admin: Address,
+        caller: Address,
         contributor_address: Address,
         delta: i64,
     ) -> Result<(), ContributorError> {
-        let stored_admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .ok_or(ContributorError::NotInitialized)?;
-        if admin != stored_admin {
+        Self::ensure_not_paused(&env)?;
+        let authorized = Self::is_admin(&env, &caller)?
+            || Self::has_role(env.clone(), caller.clone(), Role::Moderator)
+            || Self::has_role(env.clone(), caller.clone(), Role::Admin);
+        if !authorized {
             return Err(ContributorError::Unauthorized);
         }
-        admin.require_auth();
+        caller.require_auth();
         let mut contributor: ContributorData = env
             .storage()
             .persistent()
@@ -156,11 +260,65 @@ impl ContributorRegistryContract {
         contributor.reputation_score = new_score;
         env.storage()
             .persistent()
-            .set(&DataKey::Contributor(contributor_address), &contributor);
+            .set(&DataKey::Contributor(contributor_address.clone()), &contributor);
+        Self::checkpoint_reputation(&env, &contributor_address, new_score);
 
         Ok(())
     }
 
+    /// Append `(ledger, score)` to `address`'s reputation history, overwriting
+    /// the last entry if the current ledger already has a checkpoint.
+    fn checkpoint_reputation(env: &Env, address: &Address, score: u64) {
+        let key = DataKey::ReputationHistory(address.clone());
+        let mut history: Vec<ReputationCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&key)
+            .unwrap_or_else(|| Vec::new(env));
+        let ledger = env.ledger().sequence();
+        let checkpoint = (ledger, score);
+        if history.last().map(|(last_ledger, _)| last_ledger) == Some(ledger) {
+            let last_index = history.len() - 1;
+            history.set(last_index, checkpoint);
+        } else {
+            history.push_back(checkpoint);
+        }
+        env.storage().persistent().set(&key, &history);
+    }
+
+    /// Get `address`'s reputation score as of `ledger`, i.e. the score at the
+    /// most recent checkpoint whose ledger sequence is `<= ledger`. Returns 0
+    /// if no checkpoint predates it.
+    pub fn get_reputation_at(env: Env, address: Address, ledger: u32) -> u64 {
+        let history: Vec<ReputationCheckpoint> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::ReputationHistory(address))
+            .unwrap_or_else(|| Vec::new(&env));
+        if history.is_empty() {
+            return 0;
+        }
+
+        let mut low: u32 = 0;
+        let mut high: u32 = history.len();
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let (mid_ledger, _) = history.get_unchecked(mid);
+            if mid_ledger <= ledger {
+                low = mid + 1;
+            } else {
+                high = mid;
+            }
+        }
+
+        if low == 0 {
+            0
+        } else {
+            let (_, score) = history.get_unchecked(low - 1);
+            score
+        }
+    }
+
     /// Get contributor reputation
     pub fn get_reputation(env: Env, contributor: Address) -> Result<u64, ContributorError> {
         let contributor_data: ContributorData = Self::get_contributor(env, contributor)?;
@@ -191,6 +349,59 @@ impl ContributorRegistryContract {
         Self::get_contributor(env, contributor_address)
     }
 
+    /// Get the total number of registered contributors.
+    pub fn get_contributor_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ContributorCount)
+            .unwrap_or(0)
+    }
+
+    /// List registered contributors, paginated by a cursor.
+    ///
+    /// Pass the last seen address as `start_after` to continue from where a
+    /// previous page left off; `None` starts from the beginning. `limit` is
+    /// capped at [`MAX_PAGE_SIZE`].
+    pub fn list_contributors(
+        env: Env,
+        start_after: Option<Address>,
+        limit: u32,
+    ) -> Vec<ContributorData> {
+        let index_len: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::ContributorIndexLen)
+            .unwrap_or(0);
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        let mut start_index = 0u32;
+        if let Some(after) = start_after {
+            start_index = index_len;
+            for i in 0..index_len {
+                let indexed: Option<Address> =
+                    env.storage().persistent().get(&DataKey::ContributorIndex(i));
+                if indexed == Some(after.clone()) {
+                    start_index = i + 1;
+                    break;
+                }
+            }
+        }
+
+        let mut results = Vec::new(&env);
+        let mut i = start_index;
+        while i < index_len && results.len() < limit {
+            let indexed: Option<Address> =
+                env.storage().persistent().get(&DataKey::ContributorIndex(i));
+            if let Some(address) = indexed {
+                if let Ok(data) = Self::get_contributor(env.clone(), address) {
+                    results.push_back(data);
+                }
+            }
+            i += 1;
+        }
+        results
+    }
+
     /// Get admin address
     pub fn get_admin(env: Env) -> Result<Address, ContributorError> {
         env.storage()
@@ -226,13 +437,15 @@ impl ContributorRegistryContract {
         Ok(())
     }
 
-    /// Transfer the admin role to `new_admin`.
+    /// Propose `proposed` as the next admin.
     ///
-    /// Requires authorization from the current admin. Emits [`AdminChangedEvent`].
-    pub fn set_admin(
+    /// Requires authorization from the current admin. The transfer only takes
+    /// effect once `proposed` calls [`Self::accept_admin`], so a typo here
+    /// cannot brick the contract. Emits [`AdminTransferProposedEvent`].
+    pub fn propose_admin(
         env: Env,
         current_admin: Address,
-        new_admin: Address,
+        proposed: Address,
     ) -> Result<(), ContributorError> {
         let stored_admin: Address = env
             .storage()
@@ -243,15 +456,154 @@ impl ContributorRegistryContract {
             return Err(ContributorError::Unauthorized);
         }
         current_admin.require_auth();
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &proposed);
+        AdminTransferProposedEvent {
+            current_admin,
+            proposed_admin: proposed,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Accept a pending admin transfer proposed via [`Self::propose_admin`].
+    ///
+    /// Requires authorization from `proposed`. Promotes the pending admin
+    /// into [`DataKey::Admin`] and clears the pending slot. Emits
+    /// [`AdminChangedEvent`].
+    pub fn accept_admin(env: Env, proposed: Address) -> Result<(), ContributorError> {
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(ContributorError::NotInitialized)?;
+        if proposed != pending {
+            return Err(ContributorError::Unauthorized);
+        }
+        proposed.require_auth();
+        let old_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContributorError::NotInitialized)?;
+        env.storage().instance().set(&DataKey::Admin, &proposed);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
         AdminChangedEvent {
-            old_admin: current_admin,
-            new_admin,
+            old_admin,
+            new_admin: proposed,
         }
         .publish(&env);
         Ok(())
     }
+
+    /// Cancel a pending admin transfer.
+    ///
+    /// Requires authorization from the current admin.
+    pub fn cancel_admin_transfer(env: Env, current_admin: Address) -> Result<(), ContributorError> {
+        let stored_admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(ContributorError::NotInitialized)?;
+        if current_admin != stored_admin {
+            return Err(ContributorError::Unauthorized);
+        }
+        current_admin.require_auth();
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+        Ok(())
+    }
+
+    /// Get the address proposed to become the next admin, if any.
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Grant `role` to `account`.
+    ///
+    /// Admin only. Emits [`RoleGrantedEvent`].
+    pub fn grant_role(
+        env: Env,
+        admin: Address,
+        account: Address,
+        role: Role,
+    ) -> Result<(), ContributorError> {
+        if !Self::is_admin(&env, &admin)? {
+            return Err(ContributorError::Unauthorized);
+        }
+        admin.require_auth();
+        env.storage()
+            .persistent()
+            .set(&DataKey::Role(account.clone()), &role);
+        RoleGrantedEvent { account, role }.publish(&env);
+        Ok(())
+    }
+
+    /// Revoke `account`'s `role`.
+    ///
+    /// Admin only. Emits [`RoleRevokedEvent`].
+    pub fn revoke_role(
+        env: Env,
+        admin: Address,
+        account: Address,
+        role: Role,
+    ) -> Result<(), ContributorError> {
+        if !Self::is_admin(&env, &admin)? {
+            return Err(ContributorError::Unauthorized);
+        }
+        admin.require_auth();
+        let stored_role: Role = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Role(account.clone()))
+            .ok_or(ContributorError::RoleNotHeld)?;
+        if stored_role != role {
+            return Err(ContributorError::RoleNotHeld);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Role(account.clone()));
+        RoleRevokedEvent { account, role }.publish(&env);
+        Ok(())
+    }
+
+    /// Check whether `account` holds `role`.
+    pub fn has_role(env: Env, account: Address, role: Role) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, Role>(&DataKey::Role(account))
+            == Some(role)
+    }
+
+    /// Pause the contract, blocking registration and reputation writes.
+    ///
+    /// Admin only. Emits [`PausedEvent`].
+    pub fn pause(env: Env, admin: Address) -> Result<(), ContributorError> {
+        if !Self::is_admin(&env, &admin)? {
+            return Err(ContributorError::Unauthorized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &true);
+        PausedEvent { admin }.publish(&env);
+        Ok(())
+    }
+
+    /// Unpause the contract, resuming normal operation.
+    ///
+    /// Admin only. Emits [`UnpausedEvent`].
+    pub fn unpause(env: Env, admin: Address) -> Result<(), ContributorError> {
+        if !Self::is_admin(&env, &admin)? {
+            return Err(ContributorError::Unauthorized);
+        }
+        admin.require_auth();
+        env.storage().instance().set(&DataKey::Paused, &false);
+        UnpausedEvent { admin }.publish(&env);
+        Ok(())
+    }
 }
 
+#[cfg(test)]
+extern crate std;
+
 #[cfg(test)]
 mod test;
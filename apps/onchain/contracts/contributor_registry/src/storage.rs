@@ -0,0 +1,37 @@
+use soroban_sdk::{contracttype, Address, String};
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    PendingAdmin,
+    Role(Address),
+    Paused,
+    Contributor(Address),
+    GitHubIndex(String),
+    ReputationHistory(Address),
+    ContributorCount,
+    ContributorIndexLen,
+    ContributorIndex(u32),
+}
+
+/// A single reputation checkpoint: the score as of `ledger`.
+pub type ReputationCheckpoint = (u32, u64);
+
+/// A permission level an account can hold in addition to (or instead of)
+/// being the single stored admin.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Role {
+    Admin,
+    Moderator,
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct ContributorData {
+    pub address: Address,
+    pub github_handle: String,
+    pub reputation_score: u64,
+    pub registered_timestamp: u64,
+}
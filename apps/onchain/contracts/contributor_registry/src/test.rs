@@ -0,0 +1,215 @@
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    Address, Env, String,
+};
+use std::vec::Vec;
+
+use crate::{
+    errors::ContributorError, storage::Role, ContributorRegistryContract,
+    ContributorRegistryContractClient,
+};
+
+fn setup<'a>(env: &Env) -> (ContributorRegistryContractClient<'a>, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register(ContributorRegistryContract, ());
+    let client = ContributorRegistryContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+    client.initialize(&admin);
+    (client, admin)
+}
+
+fn set_ledger(env: &Env, sequence: u32) {
+    env.ledger().with_mut(|li| li.sequence_number = sequence);
+}
+
+#[test]
+fn reputation_history_binary_search_returns_score_as_of_ledger() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let contributor = Address::generate(&env);
+    client.register_contributor(&contributor, &String::from_str(&env, "octocat"));
+
+    set_ledger(&env, 10);
+    client.update_reputation(&admin, &contributor, &5);
+    set_ledger(&env, 20);
+    client.update_reputation(&admin, &contributor, &3);
+    set_ledger(&env, 20);
+    client.update_reputation(&admin, &contributor, &2);
+
+    assert_eq!(client.get_reputation_at(&contributor, &5), 0);
+    assert_eq!(client.get_reputation_at(&contributor, &10), 5);
+    assert_eq!(client.get_reputation_at(&contributor, &15), 5);
+    assert_eq!(client.get_reputation_at(&contributor, &20), 10);
+    assert_eq!(client.get_reputation_at(&contributor, &100), 10);
+    assert_eq!(client.get_reputation(&contributor), 10);
+}
+
+#[test]
+fn list_contributors_paginates_with_a_stable_cursor() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let mut addresses: Vec<Address> = Vec::new();
+    for i in 0..5 {
+        let address = Address::generate(&env);
+        let handle = std::format!("user{}", i);
+        client.register_contributor(&address, &String::from_str(&env, &handle));
+        addresses.push(address);
+    }
+
+    assert_eq!(client.get_contributor_count(), 5);
+
+    let first_page = client.list_contributors(&None, &2);
+    assert_eq!(first_page.len(), 2);
+
+    let cursor = first_page.get(1).unwrap().address.clone();
+    let second_page = client.list_contributors(&Some(cursor), &2);
+    assert_eq!(second_page.len(), 2);
+
+    let mut seen: Vec<Address> = Vec::new();
+    for c in first_page.iter() {
+        seen.push(c.address.clone());
+    }
+    for c in second_page.iter() {
+        seen.push(c.address.clone());
+    }
+    for address in addresses.iter().take(4) {
+        assert!(seen.contains(address));
+    }
+}
+
+#[test]
+fn accept_admin_promotes_pending_admin_and_clears_the_slot() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let proposed = Address::generate(&env);
+
+    client.propose_admin(&admin, &proposed);
+    assert_eq!(client.get_pending_admin(), Some(proposed.clone()));
+
+    client.accept_admin(&proposed);
+    assert_eq!(client.get_admin(), proposed);
+    assert_eq!(client.get_pending_admin(), None);
+}
+
+#[test]
+fn accept_admin_rejects_a_caller_that_is_not_the_pending_admin() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let proposed = Address::generate(&env);
+    let impostor = Address::generate(&env);
+
+    client.propose_admin(&admin, &proposed);
+    let result = client.try_accept_admin(&impostor);
+    assert_eq!(result, Err(Ok(ContributorError::Unauthorized)));
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn cancel_admin_transfer_clears_the_pending_slot() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let proposed = Address::generate(&env);
+
+    client.propose_admin(&admin, &proposed);
+    client.cancel_admin_transfer(&admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    let result = client.try_accept_admin(&proposed);
+    assert_eq!(result, Err(Ok(ContributorError::NotInitialized)));
+    assert_eq!(client.get_admin(), admin);
+}
+
+#[test]
+fn moderator_can_update_reputation_but_unrelated_account_cannot() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let contributor = Address::generate(&env);
+    client.register_contributor(&contributor, &String::from_str(&env, "octocat"));
+
+    let moderator = Address::generate(&env);
+    client.grant_role(&admin, &moderator, &Role::Moderator);
+    assert!(client.has_role(&moderator, &Role::Moderator));
+    client.update_reputation(&moderator, &contributor, &5);
+    assert_eq!(client.get_reputation(&contributor), 5);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_update_reputation(&outsider, &contributor, &5);
+    assert_eq!(result, Err(Ok(ContributorError::Unauthorized)));
+}
+
+#[test]
+fn grant_and_revoke_role_reject_non_admin_callers() {
+    let env = Env::default();
+    let (client, _admin) = setup(&env);
+    let not_admin = Address::generate(&env);
+    let account = Address::generate(&env);
+
+    let grant_result = client.try_grant_role(&not_admin, &account, &Role::Moderator);
+    assert_eq!(grant_result, Err(Ok(ContributorError::Unauthorized)));
+
+    let revoke_result = client.try_revoke_role(&not_admin, &account, &Role::Moderator);
+    assert_eq!(revoke_result, Err(Ok(ContributorError::Unauthorized)));
+}
+
+#[test]
+fn revoke_role_on_an_unheld_role_returns_role_not_held() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let account = Address::generate(&env);
+    client.grant_role(&admin, &account, &Role::Admin);
+
+    let result = client.try_revoke_role(&admin, &account, &Role::Moderator);
+    assert_eq!(result, Err(Ok(ContributorError::RoleNotHeld)));
+    assert!(client.has_role(&account, &Role::Admin));
+}
+
+#[test]
+fn pause_blocks_mutations_but_not_reads_and_unpause_restores_them() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let contributor = Address::generate(&env);
+    client.register_contributor(&contributor, &String::from_str(&env, "octocat"));
+
+    client.pause(&admin);
+
+    let register_result =
+        client.try_register_contributor(&Address::generate(&env), &String::from_str(&env, "new"));
+    assert_eq!(register_result, Err(Ok(ContributorError::ContractPaused)));
+
+    let update_result =
+        client.try_update_contributor(&contributor, &String::from_str(&env, "octocat2"));
+    assert_eq!(update_result, Err(Ok(ContributorError::ContractPaused)));
+
+    let reputation_result = client.try_update_reputation(&admin, &contributor, &5);
+    assert_eq!(reputation_result, Err(Ok(ContributorError::ContractPaused)));
+
+    let remove_result = client.try_remove_contributor(&admin, &contributor);
+    assert_eq!(remove_result, Err(Ok(ContributorError::ContractPaused)));
+
+    assert_eq!(client.get_contributor(&contributor).address, contributor);
+    assert_eq!(client.get_reputation(&contributor), 0);
+    assert_eq!(client.get_admin(), admin);
+
+    client.unpause(&admin);
+    client.update_reputation(&admin, &contributor, &5);
+    assert_eq!(client.get_reputation(&contributor), 5);
+}
+
+#[test]
+fn remove_then_reregister_does_not_duplicate_in_listing() {
+    let env = Env::default();
+    let (client, admin) = setup(&env);
+    let contributor = Address::generate(&env);
+    client.register_contributor(&contributor, &String::from_str(&env, "octocat"));
+    assert_eq!(client.get_contributor_count(), 1);
+
+    client.remove_contributor(&admin, &contributor);
+    assert_eq!(client.get_contributor_count(), 0);
+
+    client.register_contributor(&contributor, &String::from_str(&env, "octocat"));
+    assert_eq!(client.get_contributor_count(), 1);
+
+    let page = client.list_contributors(&None, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().address, contributor);
+}